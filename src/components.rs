@@ -0,0 +1,114 @@
+//! Decomposed TRS components, composed by `TransformSystem` into the local matrix.
+
+use crate::math::{Matrix3, Matrix4, UnitQuaternion, Vector3};
+
+// Local-space translation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Translation(pub Vector3<f32>);
+
+impl Translation {
+    #[inline]
+    pub fn matrix(&self) -> Matrix4<f32> {
+        Matrix4::new_translation(&self.0)
+    }
+}
+
+impl Default for Translation {
+    fn default() -> Self {
+        Translation(Vector3::new(0.0, 0.0, 0.0))
+    }
+}
+
+// Local-space rotation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rotation(pub UnitQuaternion<f32>);
+
+impl Rotation {
+    #[inline]
+    pub fn matrix(&self) -> Matrix4<f32> {
+        self.0.to_rotation_matrix().to_homogeneous()
+    }
+}
+
+impl Default for Rotation {
+    fn default() -> Self {
+        Rotation(UnitQuaternion::identity())
+    }
+}
+
+// Uniform local-space scale. If `NonUniformScale` is also present, `TransformSystem` prefers it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Scale(pub f32);
+
+impl Scale {
+    #[inline]
+    pub fn matrix(&self) -> Matrix4<f32> {
+        Matrix4::new_scaling(self.0)
+    }
+}
+
+impl Default for Scale {
+    fn default() -> Self {
+        Scale(1.0)
+    }
+}
+
+// Per-axis local-space scale. See `Scale` for the uniform equivalent.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NonUniformScale(pub Vector3<f32>);
+
+impl NonUniformScale {
+    #[inline]
+    pub fn matrix(&self) -> Matrix4<f32> {
+        Matrix4::new_nonuniform_scaling(&self.0)
+    }
+}
+
+impl Default for NonUniformScale {
+    fn default() -> Self {
+        NonUniformScale(Vector3::new(1.0, 1.0, 1.0))
+    }
+}
+
+// Reads the 3x3 upper-left basis vector at `column` out of a 4x4 matrix.
+#[inline]
+fn basis_column(matrix: &Matrix4<f32>, column: usize) -> Vector3<f32> {
+    Vector3::new(matrix[(0, column)], matrix[(1, column)], matrix[(2, column)])
+}
+
+impl Transform {
+    // The world-space translation baked into the last computed `global_matrix`.
+    pub fn world_translation(&self) -> Vector3<f32> {
+        Vector3::new(
+            self.global_matrix[(0, 3)],
+            self.global_matrix[(1, 3)],
+            self.global_matrix[(2, 3)],
+        )
+    }
+
+    // The world-space scale baked into `global_matrix`. A mirrored basis reports a negative
+    // `x` rather than hiding the mirroring behind three positive magnitudes.
+    pub fn world_scale(&self) -> Vector3<f32> {
+        let basis_x = basis_column(&self.global_matrix, 0);
+        let basis_y = basis_column(&self.global_matrix, 1);
+        let basis_z = basis_column(&self.global_matrix, 2);
+
+        let mut scale = Vector3::new(basis_x.norm(), basis_y.norm(), basis_z.norm());
+        if basis_x.dot(&basis_y.cross(&basis_z)) < 0.0 {
+            scale.x = -scale.x;
+        }
+        scale
+    }
+
+    // The world-space rotation baked into `global_matrix`, recovered by normalizing out
+    // `world_scale` from the upper-3x3 basis.
+    pub fn world_rotation(&self) -> UnitQuaternion<f32> {
+        let scale = self.world_scale();
+        let basis_x = basis_column(&self.global_matrix, 0) / scale.x;
+        let basis_y = basis_column(&self.global_matrix, 1) / scale.y;
+        let basis_z = basis_column(&self.global_matrix, 2) / scale.z;
+
+        let rotation_matrix = Matrix3::from_columns(&[basis_x, basis_y, basis_z]);
+        UnitQuaternion::from_matrix(&rotation_matrix)
+    }
+}
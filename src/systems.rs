@@ -1,10 +1,11 @@
 //! Scene graph system and types
 
 use crate::{
-    components::{Parent, Transform},
+    components::{NonUniformScale, Parent, Rotation, Scale, Transform, Translation},
     ecs::prelude::*,
     math::Matrix4,
 };
+use rayon::prelude::*;
 use std::collections::{HashMap, HashSet};
 
 #[derive(Debug)]
@@ -22,35 +23,198 @@ impl TreeNode {
     }
 }
 
-pub struct TransformSystem {}
+pub struct TransformSystem {
+    parallel_threshold: usize,
+    max_depth: usize,
+    // Shadow of each entity's last-seen `Parent` tag, so un-parenting (which
+    // `changed::<Tagged<Parent>>()` can't see) can still be detected.
+    previous_parents: HashMap<Entity, Option<Entity>>,
+}
 
 impl TransformSystem {
+    // Below this many entities, rebuild serially: spinning up rayon costs more than it saves.
+    const DEFAULT_PARALLEL_THRESHOLD: usize = 64;
+
+    // Default maximum `Parent` chain depth before a branch is aborted and reported.
+    const DEFAULT_MAX_DEPTH: usize = 256;
+
     pub fn new() -> Self {
-        TransformSystem {}
+        TransformSystem {
+            parallel_threshold: Self::DEFAULT_PARALLEL_THRESHOLD,
+            max_depth: Self::DEFAULT_MAX_DEPTH,
+            previous_parents: HashMap::new(),
+        }
+    }
+
+    // Pass `0` to always rebuild in parallel, or `usize::MAX` to never do so.
+    pub fn with_parallel_threshold(mut self, threshold: usize) -> Self {
+        self.parallel_threshold = threshold;
+        self
+    }
+
+    // Depth is only counted along children that one `explore_dfs` walk visits itself. A tree
+    // already seeded earlier in the same `run_now` (seeds are explored in query-iteration order)
+    // is adopted whole without a depth check, so the offenders list is not a reliable audit of
+    // every branch past `max_depth` — only of the ones whose depth is discovered in one walk.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    // Computes the global matrix for a single entity on demand, without a full `run_now`
+    // rebuild. Doesn't touch the stored `global_matrix`; useful mid-frame before the system
+    // has run. Returns `None` if the `Parent` chain is broken or too deep.
+    pub fn compute_global_matrix(&self, world: &World, entity: Entity) -> Option<Matrix4<f32>> {
+        const MAX_ANCESTOR_DEPTH: usize = 1024;
+
+        let mut local_matrices = Vec::new();
+        let mut current = entity;
+        loop {
+            // `Transform` is always present alongside the decomposed TRS components (it's what
+            // holds `global_matrix`), so checking for it here doubles as the liveness probe.
+            world.get_component::<Transform>(current)?;
+            local_matrices.push(TransformSystem::local_matrix(world, current));
+
+            current = match world.get_tag::<Parent>(current) {
+                Some(Parent(parent)) => *parent,
+                None => break,
+            };
+
+            if local_matrices.len() > MAX_ANCESTOR_DEPTH {
+                return None;
+            }
+        }
+
+        Some(
+            local_matrices
+                .into_iter()
+                .rev()
+                .fold(Matrix4::identity(), |global, local| global * local),
+        )
     }
 
-    pub fn run_now(&self, world: &World) {
+    // Runs the forest rebuild, returning the entities of any malformed `Parent` chains found
+    // along the way (cycles, or branches past `max_depth`) for callers to log or repair. See
+    // `with_max_depth` for why the depth-limit offenders aren't a complete audit.
+    pub fn run_now(&mut self, world: &World) -> Vec<Entity> {
         let mut forest: HashMap<Entity, TreeNode> = HashMap::new();
         let mut visited: HashSet<Entity> = HashSet::new();
-
-        let mut query =
-            <(Read<Transform>, Tagged<Parent>)>::query().filter(changed::<Tagged<Parent>>());
-        for (entity, _) in query.iter_entities(world) {
-            TransformSystem::explore_tree_dfs(entity, &mut forest, &mut visited, world);
+        let mut offenders: Vec<Entity> = Vec::new();
+
+        // Detect entities whose `Parent` link was added, changed, or removed since the last run
+        // by diffing against `previous_parents`. This is what catches un-parenting:
+        // `changed::<Tagged<Parent>>()` only matches entities that currently carry the tag, so an
+        // entity that just lost its `Parent` entirely would otherwise never get re-seeded, and
+        // would keep the stale `global_matrix` it was left with under its old parent.
+        let mut seen_entities: HashSet<Entity> = HashSet::new();
+        let mut all_query = <(Read<Transform>)>::query();
+        for (entity, _) in all_query.iter_entities(world) {
+            seen_entities.insert(entity);
+
+            let current_parent = world.get_tag::<Parent>(entity).map(|Parent(parent)| *parent);
+            let previous_parent = self.previous_parents.get(&entity).copied().flatten();
+            if current_parent != previous_parent {
+                TransformSystem::explore_tree_dfs(
+                    entity,
+                    &mut forest,
+                    &mut visited,
+                    world,
+                    self.max_depth,
+                    &mut offenders,
+                );
+            }
+            self.previous_parents.insert(entity, current_parent);
         }
+        self.previous_parents.retain(|entity, _| seen_entities.contains(entity));
 
         let mut query = <(Read<Transform>)>::query().filter(changed::<Transform>());
         for (entity, _) in query.iter_entities(world) {
-            TransformSystem::explore_tree_dfs(entity, &mut forest, &mut visited, world);
+            TransformSystem::explore_tree_dfs(
+                entity,
+                &mut forest,
+                &mut visited,
+                world,
+                self.max_depth,
+                &mut offenders,
+            );
+        }
+
+        // `Translation`/`Rotation`/`Scale`/`NonUniformScale` can each be mutated on their own
+        // without touching `Transform`, so each needs its own seed query.
+        let mut translation_query = <(Read<Translation>)>::query().filter(changed::<Translation>());
+        for (entity, _) in translation_query.iter_entities(world) {
+            TransformSystem::explore_tree_dfs(
+                entity,
+                &mut forest,
+                &mut visited,
+                world,
+                self.max_depth,
+                &mut offenders,
+            );
+        }
+
+        let mut rotation_query = <(Read<Rotation>)>::query().filter(changed::<Rotation>());
+        for (entity, _) in rotation_query.iter_entities(world) {
+            TransformSystem::explore_tree_dfs(
+                entity,
+                &mut forest,
+                &mut visited,
+                world,
+                self.max_depth,
+                &mut offenders,
+            );
+        }
+
+        let mut scale_query = <(Read<Scale>)>::query().filter(changed::<Scale>());
+        for (entity, _) in scale_query.iter_entities(world) {
+            TransformSystem::explore_tree_dfs(
+                entity,
+                &mut forest,
+                &mut visited,
+                world,
+                self.max_depth,
+                &mut offenders,
+            );
+        }
+
+        let mut non_uniform_scale_query =
+            <(Read<NonUniformScale>)>::query().filter(changed::<NonUniformScale>());
+        for (entity, _) in non_uniform_scale_query.iter_entities(world) {
+            TransformSystem::explore_tree_dfs(
+                entity,
+                &mut forest,
+                &mut visited,
+                world,
+                self.max_depth,
+                &mut offenders,
+            );
         }
 
-        // At this point the forest of transforms that need to be re-computed is built, we can
-        // par_iter over it recursively and rebuild the `global_matrix` for each.
+        // At this point the forest of transforms that need to be re-computed is built. The
+        // trees are vertex-disjoint (no `Entity` appears in more than one), so computing their
+        // global matrices can safely happen across threads. Legion's borrow API has no way to
+        // prove that disjointness statically, though, so rather than holding a `get_component_mut`
+        // across the parallel walk, each worker computes into a scratch buffer of
+        // `(Entity, Matrix4)` results and the actual writes happen afterwards in a serial pass.
         let trees: Vec<_> = forest.values().collect();
-        trees
-            // .into_par_iter()
-            .into_iter()
-            .for_each(|tree| TransformSystem::rebuild_recursive(tree, None, world));
+        let results: Vec<(Entity, Matrix4<f32>)> = if visited.len() >= self.parallel_threshold {
+            trees
+                .into_par_iter()
+                .flat_map(|tree| TransformSystem::compute_subtree_matrices(tree, None, world, true))
+                .collect()
+        } else {
+            trees
+                .into_iter()
+                .flat_map(|tree| TransformSystem::compute_subtree_matrices(tree, None, world, false))
+                .collect()
+        };
+
+        for (entity, global_matrix) in results {
+            let mut transform = world.get_component_mut::<Transform>(entity).unwrap();
+            transform.global_matrix = global_matrix;
+        }
+
+        offenders
     }
 
     #[inline]
@@ -59,6 +223,8 @@ impl TransformSystem {
         forest: &mut HashMap<Entity, TreeNode>,
         visited: &mut HashSet<Entity>,
         world: &World,
+        max_depth: usize,
+        offenders: &mut Vec<Entity>,
     ) {
         // If the node was visited already, then continue on.
         if visited.contains(&entity) {
@@ -66,66 +232,163 @@ impl TransformSystem {
         }
 
         // Explore it DFS, which will rotate any nodes it comes across that are already roots in
-        // the forest into the tree.
+        // the forest into the tree. `path` tracks the ancestors on this branch so a cycle can be
+        // recognized instead of recursed into forever.
         let mut node = TreeNode::new(entity);
-        TransformSystem::explore_dfs(&mut node, forest, visited, world);
+        let mut path = HashSet::new();
+        path.insert(entity);
+        TransformSystem::explore_dfs(&mut node, forest, visited, world, &mut path, 1, max_depth, offenders);
 
         // Add it both the forest root and mark it visited.
         forest.insert(entity, node);
         visited.insert(entity);
     }
 
+    // Marks `entity` and its whole descendant chain as visited without adding any of them to
+    // the forest, so a branch aborted for exceeding `max_depth` is left alone this run instead
+    // of a descendant further down getting independently rebuilt as a fake root.
+    #[inline]
+    fn mark_subtree_visited(entity: Entity, visited: &mut HashSet<Entity>, world: &World) {
+        if visited.contains(&entity) {
+            return;
+        }
+        visited.insert(entity);
+
+        let parent = Parent(entity);
+        let mut children_query = <(Read<Transform>)>::query().filter(tag_value(&parent));
+        for (child_entity, _) in children_query.iter_entities(world) {
+            TransformSystem::mark_subtree_visited(child_entity, visited, world);
+        }
+    }
+
     #[inline]
     fn explore_dfs(
         parent_node: &mut TreeNode,
         forest: &mut HashMap<Entity, TreeNode>,
         visited: &mut HashSet<Entity>,
         world: &World,
+        path: &mut HashSet<Entity>,
+        depth: usize,
+        max_depth: usize,
+        offenders: &mut Vec<Entity>,
     ) {
         // Iterate children with Transforms.
         let parent = Parent(parent_node.entity);
         let mut children_query = <(Read<Transform>)>::query().filter(tag_value(&parent));
         for (child_entity, _) in children_query.iter_entities(world) {
+            // A child already on the active ancestor path means `Parent` tags form a cycle.
+            // Report it and skip it rather than recursing into it forever.
+            if path.contains(&child_entity) {
+                offenders.push(child_entity);
+                continue;
+            }
+
             // Regardless of it the child is visited, if it's in the root of forest we need to
             // rotate the entire tree to a child of the parent node.
             if let Some(node) = forest.remove(&child_entity) {
-                // Add the entire tree under the root and return.
+                // Add the entire tree under the root and keep checking the other siblings.
                 parent_node.children.push(node);
-                return;
+                continue;
             }
 
-            // This node was visited already but isn't the root of a tree then stop searching.
+            // This node was visited already but isn't the root of a tree then stop searching
+            // down this child, but keep checking its siblings.
             if visited.contains(&child_entity) {
-                return;
+                continue;
+            }
+
+            // A chain this deep is almost certainly a modeling mistake rather than a real scene;
+            // abort the whole branch and report its root instead of continuing to recurse. Every
+            // descendant is marked visited (without being added to the forest) so none of them
+            // can be independently picked up as a fake root by another trigger in the same
+            // `run_now` — that would rebuild them with no ancestors at all.
+            if depth >= max_depth {
+                offenders.push(child_entity);
+                TransformSystem::mark_subtree_visited(child_entity, visited, world);
+                continue;
             }
 
             // Visit the child recursively.
             visited.insert(child_entity);
+            path.insert(child_entity);
             let mut child_node = TreeNode::new(child_entity);
-            TransformSystem::explore_dfs(&mut child_node, forest, visited, world);
+            TransformSystem::explore_dfs(
+                &mut child_node,
+                forest,
+                visited,
+                world,
+                path,
+                depth + 1,
+                max_depth,
+                offenders,
+            );
+            path.remove(&child_entity);
             parent_node.children.push(child_node);
         }
     }
 
+    // Builds the local matrix as translation * rotation * scale, defaulting absent components
+    // to identity; falls back to `Transform::matrix()` if none of the four are present.
     #[inline]
-    fn rebuild_recursive(node: &TreeNode, parent_matrix: Option<Matrix4<f32>>, world: &World) {
-        let global_matrix = {
-            if let Some(parent_matrix) = parent_matrix {
-                let mut transform = world.get_component_mut::<Transform>(node.entity).unwrap();
-                transform.global_matrix = parent_matrix * transform.matrix();
-                transform.global_matrix
-            } else {
-                let mut transform = world.get_component_mut::<Transform>(node.entity).unwrap();
-                transform.global_matrix = transform.matrix();
-                transform.global_matrix
-            }
+    fn local_matrix(world: &World, entity: Entity) -> Matrix4<f32> {
+        let translation = world.get_component::<Translation>(entity).map(|c| c.matrix());
+        let rotation = world.get_component::<Rotation>(entity).map(|c| c.matrix());
+        let scale = world
+            .get_component::<NonUniformScale>(entity)
+            .map(|c| c.matrix())
+            .or_else(|| world.get_component::<Scale>(entity).map(|c| c.matrix()));
+
+        if translation.is_some() || rotation.is_some() || scale.is_some() {
+            translation.unwrap_or_else(Matrix4::identity)
+                * rotation.unwrap_or_else(Matrix4::identity)
+                * scale.unwrap_or_else(Matrix4::identity)
+        } else {
+            world.get_component::<Transform>(entity).unwrap().matrix()
+        }
+    }
+
+    // Computes matrices for `node` and its subtree into a scratch buffer without writing back
+    // to `world`, so it's safe to run across rayon threads.
+    #[inline]
+    fn compute_subtree_matrices(
+        node: &TreeNode,
+        parent_matrix: Option<Matrix4<f32>>,
+        world: &World,
+        parallel: bool,
+    ) -> Vec<(Entity, Matrix4<f32>)> {
+        let local_matrix = TransformSystem::local_matrix(world, node.entity);
+        let global_matrix = match parent_matrix {
+            Some(parent_matrix) => parent_matrix * local_matrix,
+            None => local_matrix,
         };
 
-        // Re-compute any children in parallel.
-        // node.children.par_iter().for_each(|child| {
-        node.children.iter().for_each(|child| {
-            TransformSystem::rebuild_recursive(child, Some(global_matrix), world)
-        });
+        let mut results = vec![(node.entity, global_matrix)];
+        if parallel {
+            results.extend(
+                node.children
+                    .par_iter()
+                    .flat_map(|child| {
+                        TransformSystem::compute_subtree_matrices(
+                            child,
+                            Some(global_matrix),
+                            world,
+                            parallel,
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            );
+        } else {
+            for child in &node.children {
+                results.extend(TransformSystem::compute_subtree_matrices(
+                    child,
+                    Some(global_matrix),
+                    world,
+                    parallel,
+                ));
+            }
+        }
+
+        results
     }
 }
 
@@ -133,7 +396,7 @@ impl TransformSystem {
 mod tests {
     use super::TransformSystem;
     use crate::{
-        components::{Parent, Transform},
+        components::{NonUniformScale, Parent, Rotation, Scale, Transform, Translation},
         ecs::prelude::*,
         math::{Matrix4, Quaternion, Translation3, Unit, UnitQuaternion, Vector3},
     };
@@ -167,7 +430,7 @@ mod tests {
     // Basic default Transform's local matrix -> global matrix  (Should just be identity)
     #[test]
     fn zeroed() {
-        let (mut world, system) = transform_world();
+        let (mut world, mut system) = transform_world();
 
         let transform = Transform::default();
 
@@ -189,7 +452,7 @@ mod tests {
     // Should just put the value of the Transform's local matrix into the global matrix field.
     #[test]
     fn basic() {
-        let (mut world, system) = transform_world();
+        let (mut world, mut system) = transform_world();
 
         let mut local = Transform::default();
         local.set_translation_xyz(5.0, 5.0, 5.0);
@@ -208,7 +471,7 @@ mod tests {
     // Test Parent's global matrix * Child's local matrix -> Child's global matrix (Parent is before child)
     #[test]
     fn parent_before() {
-        let (mut world, system) = transform_world();
+        let (mut world, mut system) = transform_world();
 
         let mut local1 = Transform::default();
         local1.set_translation_xyz(5.0, 5.0, 5.0);
@@ -251,10 +514,353 @@ mod tests {
         let _a4 = together(*a3, local3.matrix());
     }
 
+    // `world_translation`/`world_rotation`/`world_scale` should recover the TRS that went into
+    // building a child's `global_matrix`, including through a non-uniform, mirrored scale.
+    #[test]
+    fn world_space_accessors_roundtrip_trs() {
+        let (mut world, mut system) = transform_world();
+
+        let mut parent = Transform::default();
+        parent.set_translation_xyz(5.0, 0.0, 0.0);
+        parent.set_rotation_euler(0.0, PI / 2.0, 0.0);
+
+        let e1 = *world.insert((), vec![(parent.clone(),)]).first().unwrap();
+
+        let mut child = Transform::default();
+        child.set_translation_xyz(1.0, 0.0, 0.0);
+        child.set_scale(Vector3::new(-2.0, 3.0, 3.0));
+
+        let e2 = *world
+            .insert((Parent(e1),), vec![(child.clone(),)])
+            .first()
+            .unwrap();
+
+        system.run_now(&world);
+
+        let expected_global = parent.matrix() * child.matrix();
+        let expected_translation = Vector3::new(
+            expected_global[(0, 3)],
+            expected_global[(1, 3)],
+            expected_global[(2, 3)],
+        );
+
+        let e2_transform = world.get_component::<Transform>(e2).unwrap();
+        assert_relative_eq!(
+            e2_transform.world_translation(),
+            expected_translation,
+            max_relative = 0.000_001,
+        );
+        assert_relative_eq!(
+            e2_transform.world_scale().abs(),
+            Vector3::new(2.0, 3.0, 3.0),
+            max_relative = 0.000_001,
+        );
+        assert!(e2_transform.world_scale().x < 0.0, "mirrored scale should flip one axis");
+        assert_relative_eq!(
+            e2_transform.world_rotation(),
+            UnitQuaternion::from_euler_angles(0.0, PI / 2.0, 0.0),
+            max_relative = 0.000_001,
+        );
+    }
+
+    // An entity carrying only a subset of Translation/Rotation/Scale should have the system
+    // compose just those, defaulting the rest to identity, instead of falling back to
+    // `Transform::matrix()`.
+    #[test]
+    fn decomposed_components_compose_local_matrix() {
+        let (mut world, mut system) = transform_world();
+
+        let translation = Translation(Vector3::new(1.0, 2.0, 3.0));
+        let scale = NonUniformScale(Vector3::new(2.0, 1.0, 1.0));
+
+        let e1 = *world
+            .insert((), vec![(Transform::default(), translation, scale)])
+            .first()
+            .unwrap();
+
+        system.run_now(&world);
+
+        let expected = translation.matrix() * scale.matrix();
+        let transform = world.get_component::<Transform>(e1).unwrap();
+        assert_eq!(*transform.global_matrix(), expected);
+    }
+
+    // `Scale` is only honored when there's no `NonUniformScale` present.
+    #[test]
+    fn non_uniform_scale_takes_priority_over_scale() {
+        let (mut world, mut system) = transform_world();
+
+        let rotation = Rotation(UnitQuaternion::from_euler_angles(0.0, PI, 0.0));
+        let scale = Scale(5.0);
+        let non_uniform_scale = NonUniformScale(Vector3::new(1.0, 2.0, 3.0));
+
+        let e1 = *world
+            .insert(
+                (),
+                vec![(Transform::default(), rotation, scale, non_uniform_scale)],
+            )
+            .first()
+            .unwrap();
+
+        system.run_now(&world);
+
+        let expected = rotation.matrix() * non_uniform_scale.matrix();
+        let transform = world.get_component::<Transform>(e1).unwrap();
+        assert_eq!(*transform.global_matrix(), expected);
+    }
+
+    // Mutating just `Translation` (never touching `Transform` itself) must still get picked up
+    // on the next `run_now`.
+    #[test]
+    fn mutating_translation_alone_reseeds_global_matrix() {
+        let (mut world, mut system) = transform_world();
+
+        let translation = Translation(Vector3::new(1.0, 0.0, 0.0));
+
+        let e1 = *world
+            .insert((), vec![(Transform::default(), translation)])
+            .first()
+            .unwrap();
+
+        system.run_now(&world);
+
+        let new_translation = Translation(Vector3::new(0.0, 2.0, 0.0));
+        let mut translation_ref = world.get_component_mut::<Translation>(e1).unwrap();
+        translation_ref.0 = new_translation.0;
+        drop(translation_ref);
+
+        system.run_now(&world);
+
+        let expected = new_translation.matrix();
+        let transform = world.get_component::<Transform>(e1).unwrap();
+        assert_eq!(*transform.global_matrix(), expected);
+    }
+
+    // Confirms `compute_global_matrix` matches a full `run_now` rebuild without requiring one,
+    // and that it returns `None` for an entity whose `Parent` points at a missing ancestor.
+    #[test]
+    fn compute_global_matrix_on_demand() {
+        let (mut world, system) = transform_world();
+
+        let mut local1 = Transform::default();
+        local1.set_translation_xyz(5.0, 5.0, 5.0);
+
+        let e1 = *world.insert((), vec![(local1.clone(),)]).first().unwrap();
+
+        let mut local2 = Transform::default();
+        local2.set_translation_xyz(1.0, 0.0, 0.0);
+
+        let e2 = *world
+            .insert((Parent(e1),), vec![(local2.clone(),)])
+            .first()
+            .unwrap();
+
+        // Queried before `run_now` ever executes: there is no stored `global_matrix` to rely on.
+        let expected = local1.matrix() * local2.matrix();
+        assert_eq!(system.compute_global_matrix(&world, e2).unwrap(), expected);
+
+        // An entity parented to one that has since been removed should report `None` rather
+        // than panicking.
+        world.delete(e1);
+        assert!(system.compute_global_matrix(&world, e2).is_none());
+    }
+
+    // Builds `width` children under `parent` and `width` under each of those, `depth` levels in
+    // all (`depth == 0` builds nothing).
+    fn spawn_children(
+        world: &mut World,
+        parent: Entity,
+        depth: usize,
+        width: usize,
+        counter: &mut u32,
+        entities: &mut Vec<Entity>,
+    ) {
+        if depth == 0 {
+            return;
+        }
+        for _ in 0..width {
+            *counter += 1;
+            let mut transform = Transform::default();
+            transform.set_translation_xyz(*counter as f32 * 0.01, 0.0, 0.0);
+            let child = *world
+                .insert((Parent(parent),), vec![(transform,)])
+                .first()
+                .unwrap();
+            entities.push(child);
+            spawn_children(world, child, depth - 1, width, counter, entities);
+        }
+    }
+
+    // Stress test: a handful of wide/deep disjoint trees rebuilt via the rayon-parallel path
+    // should produce exactly the same `global_matrix`es as the serial path.
+    #[test]
+    fn parallel_matches_serial_on_wide_deep_hierarchy() {
+        const ROOTS: usize = 4;
+        const DEPTH: usize = 4;
+        const WIDTH: usize = 3;
+
+        let build = || {
+            let mut world = Universe::new().create_world();
+            let mut counter = 0u32;
+            let mut entities = Vec::new();
+            for _ in 0..ROOTS {
+                counter += 1;
+                let mut transform = Transform::default();
+                transform.set_translation_xyz(counter as f32 * 0.01, 0.0, 0.0);
+                let root = *world.insert((), vec![(transform,)]).first().unwrap();
+                entities.push(root);
+                spawn_children(&mut world, root, DEPTH, WIDTH, &mut counter, &mut entities);
+            }
+            (world, entities)
+        };
+
+        let (serial_world, entities) = build();
+        TransformSystem::new()
+            .with_parallel_threshold(usize::MAX)
+            .run_now(&serial_world);
+
+        let (parallel_world, _) = build();
+        TransformSystem::new()
+            .with_parallel_threshold(0)
+            .run_now(&parallel_world);
+
+        for &entity in &entities {
+            let serial = serial_world
+                .get_component::<Transform>(entity)
+                .unwrap()
+                .global_matrix;
+            let parallel = parallel_world
+                .get_component::<Transform>(entity)
+                .unwrap()
+                .global_matrix;
+            assert_relative_eq!(serial, parallel, max_relative = 0.000_001);
+        }
+    }
+
+    // A direct 2-cycle (e1 parented to e2, e2 parented to e1) must not hang `run_now` and must
+    // report one of the cycle's members.
+    #[test]
+    fn cycle_detection_direct() {
+        let (mut world, mut system) = transform_world();
+
+        let e1 = *world.insert((), vec![(Transform::default(),)]).first().unwrap();
+        let e2 = *world.insert((), vec![(Transform::default(),)]).first().unwrap();
+
+        world.add_tag(e1, Parent(e2));
+        world.add_tag(e2, Parent(e1));
+
+        let offenders = system.run_now(&world);
+
+        assert!(offenders.contains(&e1) || offenders.contains(&e2));
+    }
+
+    // A longer 3-cycle (e1 -> e2 -> e3 -> e1) must likewise terminate and report a member.
+    #[test]
+    fn cycle_detection_longer_chain() {
+        let (mut world, mut system) = transform_world();
+
+        let e1 = *world.insert((), vec![(Transform::default(),)]).first().unwrap();
+        let e2 = *world.insert((), vec![(Transform::default(),)]).first().unwrap();
+        let e3 = *world.insert((), vec![(Transform::default(),)]).first().unwrap();
+
+        world.add_tag(e1, Parent(e2));
+        world.add_tag(e2, Parent(e3));
+        world.add_tag(e3, Parent(e1));
+
+        let offenders = system.run_now(&world);
+
+        assert!(
+            offenders.contains(&e1) || offenders.contains(&e2) || offenders.contains(&e3)
+        );
+    }
+
+    // A `Parent` chain deeper than `max_depth` should have its branch aborted and reported,
+    // rather than rebuilding arbitrarily deep hierarchies.
+    #[test]
+    fn depth_limit_aborts_branch() {
+        let (mut world, _) = transform_world();
+        let mut system = TransformSystem::new().with_max_depth(2);
+
+        let mut parent = None;
+        let mut entities = Vec::new();
+        for _ in 0..5 {
+            let mut transform = Transform::default();
+            transform.set_translation_xyz(1.0, 0.0, 0.0);
+            let entity = match parent {
+                Some(p) => *world
+                    .insert((Parent(p),), vec![(transform,)])
+                    .first()
+                    .unwrap(),
+                None => *world.insert((), vec![(transform,)]).first().unwrap(),
+            };
+            entities.push(entity);
+            parent = Some(entity);
+        }
+
+        let offenders = system.run_now(&world);
+
+        assert!(!offenders.is_empty());
+
+        // Which entity the abort lands on depends on query iteration order (it's whichever one
+        // happens to be explored first), so rather than pinning an exact entity, check the
+        // invariant the fix guarantees: every entity ends the run either correctly rebuilt
+        // relative to the *real* root (never parented, so never subject to the depth limit) or
+        // left completely untouched by this run. What must never happen is the bug this fixes:
+        // an aborted entity getting independently rebuilt as a fake root, i.e. ending up with
+        // just its own local translation instead of its real cumulative one.
+        let identity = *Transform::default().global_matrix();
+        for (i, &entity) in entities.iter().enumerate() {
+            let global_matrix = world.get_component::<Transform>(entity).unwrap().global_matrix;
+            let correct: Matrix4<f32> = Translation3::new((i + 1) as f32, 0.0, 0.0).into();
+            assert!(
+                relative_eq!(global_matrix, correct, max_relative = 0.000_001)
+                    || relative_eq!(global_matrix, identity, max_relative = 0.000_001),
+                "entity {} had neither its correct cumulative matrix nor its untouched default: {:?}",
+                i,
+                global_matrix,
+            );
+        }
+    }
+
+    // Un-parenting an entity whose own `Transform` never changes must still reset its stale
+    // `global_matrix` (baked in under the old parent) back to its local matrix.
+    #[test]
+    fn unparenting_resets_global_matrix() {
+        let (mut world, mut system) = transform_world();
+
+        let mut parent_transform = Transform::default();
+        parent_transform.set_translation_xyz(5.0, 0.0, 0.0);
+        let parent = *world
+            .insert((), vec![(parent_transform.clone(),)])
+            .first()
+            .unwrap();
+
+        let mut child_transform = Transform::default();
+        child_transform.set_translation_xyz(1.0, 0.0, 0.0);
+        let child = *world
+            .insert((Parent(parent),), vec![(child_transform.clone(),)])
+            .first()
+            .unwrap();
+
+        system.run_now(&world);
+        assert_eq!(
+            *world.get_component::<Transform>(child).unwrap().global_matrix(),
+            parent_transform.matrix() * child_transform.matrix(),
+        );
+
+        world.remove_tag::<Parent>(child);
+        system.run_now(&world);
+
+        assert_eq!(
+            *world.get_component::<Transform>(child).unwrap().global_matrix(),
+            child_transform.matrix(),
+        );
+    }
+
     /// Tests that re-parenting transforms correctly causes descendants to be re-computed.
     #[test]
     fn reparenting() {
-        let system = TransformSystem::new();
+        let mut system = TransformSystem::new();
         let mut world = Universe::new().create_world();
 
         // Create a translation and a rotation transform.